@@ -1,4 +1,10 @@
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
 use borsh::{BorshDeserialize, BorshSerialize};
+use lru::LruCache;
 use namada_core::ledger::eth_bridge::storage::active_key;
 use namada_core::ledger::eth_bridge::storage::bridge_pool::{
     get_nonce_key, get_signed_root_key,
@@ -8,7 +14,7 @@ use namada_core::ledger::storage::{Storage, StoreType};
 use namada_core::types::address::Address;
 use namada_core::types::ethereum_events::{EthAddress, Uint};
 use namada_core::types::keccak::KeccakHash;
-use namada_core::types::storage::{BlockHeight, Epoch};
+use namada_core::types::storage::{BlockHeight, DbKeySeg, Epoch, Key};
 use namada_core::types::token;
 use namada_core::types::vote_extensions::validator_set_update::{
     EthAddrBook, ValidatorSetArgs, VotingPowersMap, VotingPowersMapExt,
@@ -19,8 +25,626 @@ use namada_core::types::voting_power::{
 use namada_proof_of_stake::pos_queries::PosQueries;
 use namada_proof_of_stake::PosBase;
 
+use crate::storage::eth_light_client::{
+    light_client_key, LightClientStore, LightClientUpdate,
+};
 use crate::storage::proof::EthereumProof;
 
+/// Identifies one of the (possibly many) Ethereum-like chains that Namada
+/// maintains a bridge to, mirroring the instanced-pallet model on the
+/// Ethereum side (e.g. distinct `BridgeKovanConfig`/`BridgeRialtoConfig`
+/// instances). Concretely, this is the EVM chain ID of the target chain.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    BorshDeserialize,
+    BorshSerialize,
+)]
+pub struct BridgeInstanceId(pub u64);
+
+/// A Merkle inclusion proof for a single transfer in the Ethereum bridge
+/// pool, together with everything a relayer needs to submit and withdraw
+/// the transfer on the Ethereum side in one shot.
+#[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
+pub struct BridgePoolProof {
+    /// The index of the transfer's leaf in the Bridge pool Merkle tree.
+    pub leaf_index: u64,
+    /// Ordered sibling hashes on the path from the leaf up to the root,
+    /// proving inclusion of the transfer in the Bridge pool Merkle tree.
+    pub sibling_hashes: Vec<KeccakHash>,
+    /// The root of the Bridge pool Merkle tree the proof was taken
+    /// against, the pool's nonce, and a quorum of validator signatures
+    /// attesting to both.
+    pub signed_root: EthereumProof<(KeccakHash, Uint)>,
+}
+
+/// Number of epochs' worth of validator set / active address data kept
+/// per bridge instance in [`VALSET_CACHE`] and [`ACTIVE_ADDRESSES_CACHE`].
+/// Vote extension handling in Prepare/ProcessProposal only ever looks at
+/// the current (and occasionally the previous) epoch, so a couple of
+/// entries per instance is enough to avoid rebuilding on every call
+/// within an epoch.
+const EPOCH_CACHE_SIZE: usize = 2;
+
+/// A token identifying one long-lived `Storage` instance to the caches
+/// in this module, minted once (via [`EthBridgeCacheScope::new`]) by
+/// whoever constructs that `Storage` and passed into every
+/// [`EthBridgeQueries::get_validator_set_args`]/
+/// [`EthBridgeQueries::get_active_eth_addresses`] call made against it.
+///
+/// This crate doesn't own the `Storage` struct, so it can't hook its
+/// construction or drop to mint an id automatically, and a `Storage`'s
+/// own address isn't a safe substitute: once a `Storage` is dropped
+/// (e.g. between test cases), a later, unrelated `Storage` can be
+/// allocated at the same address, and an address-keyed cache would
+/// silently hand the new instance the old one's cached validator sets -
+/// consensus-relevant data. [`EthBridgeCacheScope::new`] mints from a
+/// monotonically increasing counter instead, so every scope is unique
+/// for the life of the process regardless of any `Storage`'s address
+/// being reused; it is up to the caller to construct exactly one scope
+/// per long-lived `Storage` (e.g. once at node startup, or once per test
+/// fixture) and keep reusing it, rather than minting a fresh scope on
+/// every call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EthBridgeCacheScope(usize);
+
+impl EthBridgeCacheScope {
+    /// Mint a fresh scope, guaranteed distinct from every other scope
+    /// minted in this process.
+    pub fn new() -> Self {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl Default for EthBridgeCacheScope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One instance's slot in the caches: which [`EthBridgeCacheScope`],
+/// which bridge instance. Each instance gets its own small per-epoch LRU
+/// (see [`EPOCH_CACHE_SIZE`]), so bridge instances scale the cache
+/// rather than competing with each other for a fixed number of slots.
+type InstanceKey = (EthBridgeCacheScope, BridgeInstanceId);
+
+/// Per-scope, per-instance, per-epoch cache of [`ValidatorSetArgs`],
+/// populated on first access and evicted once entries older than the
+/// current epoch fall out of the LRU. This avoids rebuilding the full
+/// validator set (which re-reads every validator's hot/cold keys,
+/// re-derives the sorted [`VotingPowersMap`], and re-sorts by voting
+/// power) on every call within the same epoch.
+#[allow(clippy::type_complexity)]
+static VALSET_CACHE: OnceLock<
+    Mutex<HashMap<InstanceKey, LruCache<Epoch, Arc<ValidatorSetArgs>>>>,
+> = OnceLock::new();
+
+/// Per-scope, per-instance, per-epoch cache of active Ethereum address
+/// books, with the same invalidation story as [`VALSET_CACHE`].
+#[allow(clippy::type_complexity)]
+static ACTIVE_ADDRESSES_CACHE: OnceLock<
+    Mutex<
+        HashMap<
+            InstanceKey,
+            LruCache<Epoch, Arc<Vec<(EthAddrBook, Address, token::Amount)>>>,
+        >,
+    >,
+> = OnceLock::new();
+
+fn valset_cache(
+) -> &'static Mutex<HashMap<InstanceKey, LruCache<Epoch, Arc<ValidatorSetArgs>>>>
+{
+    VALSET_CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn active_addresses_cache() -> &'static Mutex<
+    HashMap<
+        InstanceKey,
+        LruCache<Epoch, Arc<Vec<(EthAddrBook, Address, token::Amount)>>>,
+    >,
+> {
+    ACTIVE_ADDRESSES_CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Drop any cached entries of `scope` for epochs strictly before
+/// `current_epoch`, so that a validator set or active address book can
+/// never be served stale across an epoch boundary.
+fn invalidate_stale_eth_bridge_caches(
+    scope: EthBridgeCacheScope,
+    current_epoch: Epoch,
+) {
+    let mut valsets = valset_cache().lock().unwrap();
+    for lru in valsets
+        .iter_mut()
+        .filter(|((s, _), _)| *s == scope)
+        .map(|(_, lru)| lru)
+    {
+        let stale: Vec<Epoch> = lru
+            .iter()
+            .filter(|(&epoch, _)| epoch < current_epoch)
+            .map(|(&epoch, _)| epoch)
+            .collect();
+        for epoch in stale {
+            lru.pop(&epoch);
+        }
+    }
+    drop(valsets);
+
+    let mut addresses = active_addresses_cache().lock().unwrap();
+    for lru in addresses
+        .iter_mut()
+        .filter(|((s, _), _)| *s == scope)
+        .map(|(_, lru)| lru)
+    {
+        let stale: Vec<Epoch> = lru
+            .iter()
+            .filter(|(&epoch, _)| epoch < current_epoch)
+            .map(|(&epoch, _)| epoch)
+            .collect();
+        for epoch in stale {
+            lru.pop(&epoch);
+        }
+    }
+}
+
+#[cfg(test)]
+mod eth_bridge_cache_tests {
+    use super::*;
+
+    fn new_epoch_lru<V>() -> LruCache<Epoch, V> {
+        LruCache::new(NonZeroUsize::new(EPOCH_CACHE_SIZE).unwrap())
+    }
+
+    /// Entries older than the current epoch must be evicted, and distinct
+    /// bridge instances under the same scope must not thrash each other
+    /// out of their own per-instance LRU.
+    #[test]
+    fn invalidate_stale_caches_evicts_old_epochs_only() {
+        let scope = EthBridgeCacheScope::new();
+        let instance_a = BridgeInstanceId(1);
+        let instance_b = BridgeInstanceId(2);
+
+        let mut cache = active_addresses_cache().lock().unwrap();
+        let mut lru_a = new_epoch_lru();
+        lru_a.put(Epoch(1), Arc::new(vec![]));
+        cache.insert((scope, instance_a), lru_a);
+        let mut lru_b = new_epoch_lru();
+        lru_b.put(Epoch(5), Arc::new(vec![]));
+        cache.insert((scope, instance_b), lru_b);
+        drop(cache);
+
+        // epoch advances past instance_a's entry, but not instance_b's
+        invalidate_stale_eth_bridge_caches(scope, Epoch(2));
+        let cache = active_addresses_cache().lock().unwrap();
+        assert!(cache
+            .get(&(scope, instance_a))
+            .unwrap()
+            .peek(&Epoch(1))
+            .is_none());
+        assert!(cache
+            .get(&(scope, instance_b))
+            .unwrap()
+            .peek(&Epoch(5))
+            .is_some());
+    }
+
+    /// A cache entry belonging to a different scope must never be evicted
+    /// or read back as if it belonged to another `Storage` instance.
+    #[test]
+    fn caches_are_scoped_per_storage() {
+        let instance = BridgeInstanceId(1);
+        let scope_1 = EthBridgeCacheScope::new();
+        let scope_2 = EthBridgeCacheScope::new();
+
+        let mut cache = active_addresses_cache().lock().unwrap();
+        let mut lru_1 = new_epoch_lru();
+        lru_1.put(Epoch(5), Arc::new(vec![]));
+        cache.insert((scope_1, instance), lru_1);
+        let mut lru_2 = new_epoch_lru();
+        lru_2.put(Epoch(5), Arc::new(vec![]));
+        cache.insert((scope_2, instance), lru_2);
+        drop(cache);
+
+        // advancing scope_2 past epoch 5 must not touch scope_1's entry
+        // for the same epoch
+        invalidate_stale_eth_bridge_caches(scope_2, Epoch(6));
+        assert!(active_addresses_cache()
+            .lock()
+            .unwrap()
+            .get(&(scope_1, instance))
+            .unwrap()
+            .peek(&Epoch(5))
+            .is_some());
+    }
+
+    /// Two scopes minted back-to-back (the normal shape of two `Storage`
+    /// instances sharing a process, e.g. a test harness spinning up fresh
+    /// storage per case) must never collide, regardless of what epoch
+    /// either instance reports.
+    #[test]
+    fn freshly_minted_scopes_never_collide() {
+        let first = EthBridgeCacheScope::new();
+        let second = EthBridgeCacheScope::new();
+        assert_ne!(first, second);
+    }
+}
+
+/// Apply `update` to the Ethereum light client state stored under
+/// [`light_client_key`], persisting the result and returning `true` on
+/// success.
+///
+/// Returns `false`, without writing anything, if the update fails any of
+/// the checks in [`LightClientStore::apply_update`], or if no light
+/// client state has been bootstrapped under [`light_client_key`] yet.
+/// See the write-path note on [`write_eth_bridge_status`] for the
+/// genesis bootstrapping and call-site work this is still waiting on.
+pub fn apply_and_store_light_client_update<D, H>(
+    storage: &mut Storage<D, H>,
+    update: &LightClientUpdate,
+    fork_version: [u8; 4],
+    genesis_validators_root: KeccakHash,
+) -> bool
+where
+    D: storage::DB + for<'iter> storage::DBIter<'iter>,
+    H: storage::StorageHasher,
+{
+    let Some(bytes) = storage
+        .read(&light_client_key())
+        .expect("Reading the Ethereum light client state shouldn't fail.")
+        .0
+    else {
+        return false;
+    };
+    let mut store: LightClientStore = BorshDeserialize::try_from_slice(&bytes)
+        .expect(
+            "Deserializing the Ethereum light client state shouldn't fail.",
+        );
+
+    if !store.apply_update(update, fork_version, genesis_validators_root) {
+        return false;
+    }
+
+    storage
+        .write(
+            &light_client_key(),
+            store.try_to_vec().expect(
+                "Serializing the Ethereum light client state shouldn't fail",
+            ),
+        )
+        .expect("Writing the Ethereum light client state shouldn't fail.");
+    true
+}
+
+/// The storage key holding the quorum of validator signatures over the
+/// validator set hash for `epoch`, mirroring
+/// [`namada_core::ledger::eth_bridge::storage::bridge_pool::get_signed_root_key`]
+/// for the bridge pool root.
+fn signed_valset_upd_key(epoch: Epoch) -> Key {
+    Key::parse("eth_bridge/validator_set_update")
+        .expect(
+            "Constructing the validator set update storage key shouldn't \
+             fail",
+        )
+        .push(&DbKeySeg::StringSeg(epoch.to_string()))
+        .expect("Pushing the epoch segment shouldn't fail")
+}
+
+/// Write the quorum of validator signatures over `instance`'s validator
+/// set hash for `epoch` under [`signed_valset_upd_key`], so that
+/// [`EthBridgeQueries::get_signed_valset_upd_proof`] can serve it back to
+/// a relayer reconstructing a historical validator set update. See the
+/// write-path note on [`write_eth_bridge_status`] for the caller this is
+/// still waiting on.
+pub fn write_signed_valset_upd_proof<D, H>(
+    storage: &mut Storage<D, H>,
+    instance: BridgeInstanceId,
+    epoch: Epoch,
+    proof: &EthereumProof<ValidatorSetArgs>,
+) where
+    D: storage::DB + for<'iter> storage::DBIter<'iter>,
+    H: storage::StorageHasher,
+{
+    storage
+        .write(
+            &instance.scope(signed_valset_upd_key(epoch)),
+            proof.try_to_vec().expect(
+                "Serializing a signed validator set update proof shouldn't \
+                 fail",
+            ),
+        )
+        .expect(
+            "Writing a signed validator set update proof shouldn't fail.",
+        );
+}
+
+/// Read `key` scoped to `instance`, falling back to the legacy unscoped
+/// key if nothing has been written to the scoped path yet. An instance
+/// provisioned via [`write_eth_bridge_status`] reads back its own value;
+/// one that hasn't falls back to the one pre-existing bridge's value
+/// here rather than panicking.
+fn read_scoped_or_legacy<D, H>(
+    storage: &Storage<D, H>,
+    instance: BridgeInstanceId,
+    key: &Key,
+) -> Option<Vec<u8>>
+where
+    D: storage::DB + for<'iter> storage::DBIter<'iter>,
+    H: storage::StorageHasher,
+{
+    storage
+        .read(&instance.scope(key.clone()))
+        .expect("Reading a per-instance-scoped storage key shouldn't fail.")
+        .0
+        .or_else(|| {
+            storage
+                .read(key)
+                .expect("Reading a storage key shouldn't fail.")
+                .0
+        })
+}
+
+/// Same fallback as [`read_scoped_or_legacy`], but reading the value as it
+/// stood at a past `height` rather than the latest value.
+fn read_scoped_or_legacy_at_height<D, H>(
+    storage: &Storage<D, H>,
+    instance: BridgeInstanceId,
+    key: &Key,
+    height: BlockHeight,
+) -> Option<Vec<u8>>
+where
+    D: storage::DB + for<'iter> storage::DBIter<'iter>,
+    H: storage::StorageHasher,
+{
+    storage
+        .db
+        .read_subspace_val_with_height(
+            &instance.scope(key.clone()),
+            height,
+            storage.last_height,
+        )
+        .expect("Reading a per-instance-scoped storage key shouldn't fail.")
+        .or_else(|| {
+            storage
+                .db
+                .read_subspace_val_with_height(key, height, storage.last_height)
+                .expect("Reading a storage key shouldn't fail.")
+        })
+}
+
+impl BridgeInstanceId {
+    /// Push this instance's segment onto a storage [`Key`], so that each
+    /// bridge instance is kept in its own subspace.
+    fn scope(self, key: Key) -> Key {
+        key.push(&DbKeySeg::StringSeg(format!("bridge-instance-{}", self.0)))
+            .expect("Pushing a bridge instance segment should not fail")
+    }
+}
+
+/// The storage key under which `instance`'s override of the Bridge pool
+/// Merkle root may be written, distinct from the single shared
+/// `StoreType::BridgePool` sub-tree every instance falls back to until
+/// [`write_bridge_pool_root_override`] has been called for it.
+fn bridge_pool_root_key(instance: BridgeInstanceId) -> Key {
+    instance.scope(
+        Key::parse("eth_bridge/bridge_pool_root").expect(
+            "Constructing the bridge pool root override key shouldn't fail",
+        ),
+    )
+}
+
+/// The storage key under which `instance`'s override of `validator`'s
+/// Ethereum bridge (hot) address may be written, letting a validator's
+/// hot key mapping differ per target chain, until
+/// [`write_eth_hot_key_override`] has been called for it.
+fn eth_hot_key_override_key(
+    instance: BridgeInstanceId,
+    validator: &Address,
+) -> Key {
+    instance.scope(
+        Key::parse("eth_bridge/hot_key_override")
+            .expect("Constructing the hot key override key shouldn't fail")
+            .push(&DbKeySeg::StringSeg(validator.to_string()))
+            .expect("Pushing the validator segment shouldn't fail"),
+    )
+}
+
+/// The storage key under which `instance`'s override of `validator`'s
+/// Ethereum governance (cold) address may be written, mirroring
+/// [`eth_hot_key_override_key`].
+fn eth_cold_key_override_key(
+    instance: BridgeInstanceId,
+    validator: &Address,
+) -> Key {
+    instance.scope(
+        Key::parse("eth_bridge/cold_key_override")
+            .expect("Constructing the cold key override key shouldn't fail")
+            .push(&DbKeySeg::StringSeg(validator.to_string()))
+            .expect("Pushing the validator segment shouldn't fail"),
+    )
+}
+
+/// Write `instance`'s [`EthBridgeStatus`] under its own scoped subspace,
+/// so [`EthBridgeQueries::check_bridge_status`] serves a value distinct
+/// from the single pre-existing bridge's status for this instance going
+/// forward, rather than falling through to the legacy unscoped key.
+///
+/// ## Write-path note
+///
+/// This function and its siblings below it
+/// ([`write_bridge_pool_root_override`], [`write_bridge_pool_nonce_override`],
+/// [`write_signed_bridge_pool_root`], [`write_eth_hot_key_override`],
+/// [`write_eth_cold_key_override`]), plus
+/// [`write_signed_valset_upd_proof`] and
+/// [`apply_and_store_light_client_update`] elsewhere in this crate, are
+/// all genuine storage writes with no
+/// caller anywhere in this series yet. Wiring genesis/governance
+/// provisioning, the live validator-set-update quorum tally, and the
+/// light client update handler to call these is tracked as explicit
+/// follow-up work outside this crate, not implied by any of these
+/// functions existing.
+pub fn write_eth_bridge_status<D, H>(
+    storage: &mut Storage<D, H>,
+    instance: BridgeInstanceId,
+    status: &EthBridgeStatus,
+) where
+    D: storage::DB + for<'iter> storage::DBIter<'iter>,
+    H: storage::StorageHasher,
+{
+    storage
+        .write(
+            &instance.scope(active_key()),
+            status
+                .try_to_vec()
+                .expect("Serializing an EthBridgeStatus shouldn't fail"),
+        )
+        .expect("Writing a per-instance Ethereum bridge status shouldn't fail.");
+}
+
+/// Write `instance`'s override of the Bridge pool Merkle root. See the
+/// write-path note on [`write_eth_bridge_status`].
+pub fn write_bridge_pool_root_override<D, H>(
+    storage: &mut Storage<D, H>,
+    instance: BridgeInstanceId,
+    root: &KeccakHash,
+) where
+    D: storage::DB + for<'iter> storage::DBIter<'iter>,
+    H: storage::StorageHasher,
+{
+    storage
+        .write(
+            &bridge_pool_root_key(instance),
+            root.try_to_vec()
+                .expect("Serializing a KeccakHash shouldn't fail"),
+        )
+        .expect("Writing a per-instance bridge pool root shouldn't fail.");
+}
+
+/// Write `instance`'s override of `validator`'s Ethereum bridge (hot)
+/// address. See the write-path note on [`write_eth_bridge_status`].
+pub fn write_eth_hot_key_override<D, H>(
+    storage: &mut Storage<D, H>,
+    instance: BridgeInstanceId,
+    validator: &Address,
+    hot_key_addr: &EthAddress,
+) where
+    D: storage::DB + for<'iter> storage::DBIter<'iter>,
+    H: storage::StorageHasher,
+{
+    storage
+        .write(
+            &eth_hot_key_override_key(instance, validator),
+            hot_key_addr
+                .try_to_vec()
+                .expect("Serializing an EthAddress shouldn't fail"),
+        )
+        .expect("Writing a per-instance hot key override shouldn't fail.");
+}
+
+/// Write `instance`'s override of `validator`'s Ethereum governance
+/// (cold) address, mirroring [`write_eth_hot_key_override`].
+pub fn write_eth_cold_key_override<D, H>(
+    storage: &mut Storage<D, H>,
+    instance: BridgeInstanceId,
+    validator: &Address,
+    cold_key_addr: &EthAddress,
+) where
+    D: storage::DB + for<'iter> storage::DBIter<'iter>,
+    H: storage::StorageHasher,
+{
+    storage
+        .write(
+            &eth_cold_key_override_key(instance, validator),
+            cold_key_addr
+                .try_to_vec()
+                .expect("Serializing an EthAddress shouldn't fail"),
+        )
+        .expect("Writing a per-instance cold key override shouldn't fail.");
+}
+
+/// Write `instance`'s override of the Bridge pool nonce. See the
+/// write-path note on [`write_eth_bridge_status`].
+pub fn write_bridge_pool_nonce_override<D, H>(
+    storage: &mut Storage<D, H>,
+    instance: BridgeInstanceId,
+    nonce: &Uint,
+) where
+    D: storage::DB + for<'iter> storage::DBIter<'iter>,
+    H: storage::StorageHasher,
+{
+    storage
+        .write(
+            &instance.scope(get_nonce_key()),
+            nonce.try_to_vec().expect("Serializing a Uint shouldn't fail"),
+        )
+        .expect("Writing a per-instance bridge pool nonce shouldn't fail.");
+}
+
+/// Write the quorum of validator signatures over `instance`'s bridge
+/// pool root and nonce. See the write-path note on
+/// [`write_eth_bridge_status`].
+pub fn write_signed_bridge_pool_root<D, H>(
+    storage: &mut Storage<D, H>,
+    instance: BridgeInstanceId,
+    signed_root: &EthereumProof<(KeccakHash, Uint)>,
+) where
+    D: storage::DB + for<'iter> storage::DBIter<'iter>,
+    H: storage::StorageHasher,
+{
+    storage
+        .write(
+            &instance.scope(get_signed_root_key()),
+            signed_root.try_to_vec().expect(
+                "Serializing a signed bridge pool root shouldn't fail",
+            ),
+        )
+        .expect(
+            "Writing a per-instance signed bridge pool root shouldn't fail.",
+        );
+}
+
+/// Whether `height` is a height [`EthBridgeQueries::get_bridge_pool_inclusion_proof`]
+/// can build a verifiable proof for, i.e. whether it is the chain's
+/// current tip.
+///
+/// [`EthBridgeQueries::get_signed_bridge_pool_root`] only ever has the
+/// latest signed root and nonce to bundle into a [`BridgePoolProof`], so
+/// a proof whose sibling path was reconstructed against an earlier
+/// height's tree would not reconstruct to the root it ships with.
+fn inclusion_proof_height_is_supported(
+    height: BlockHeight,
+    last_height: BlockHeight,
+) -> bool {
+    height == last_height
+}
+
+#[cfg(test)]
+mod bridge_pool_inclusion_proof_tests {
+    use super::*;
+
+    #[test]
+    fn proof_is_supported_only_at_the_current_tip() {
+        assert!(inclusion_proof_height_is_supported(
+            BlockHeight(10),
+            BlockHeight(10)
+        ));
+        assert!(!inclusion_proof_height_is_supported(
+            BlockHeight(9),
+            BlockHeight(10)
+        ));
+        assert!(!inclusion_proof_height_is_supported(
+            BlockHeight(11),
+            BlockHeight(10)
+        ));
+    }
+}
+
 /// This enum is used as a parameter to
 /// [`EthBridgeQueries::must_send_valset_upd`].
 pub enum SendValsetUpd {
@@ -33,7 +657,8 @@ pub enum SendValsetUpd {
 }
 
 #[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
-/// An enum indicating if the Ethereum bridge is enabled.
+/// An enum indicating if the Ethereum bridge is enabled, for some
+/// particular [`BridgeInstanceId`].
 pub enum EthBridgeStatus {
     Disabled,
     Enabled(EthBridgeEnabled),
@@ -52,44 +677,152 @@ pub enum EthBridgeEnabled {
     ),
 }
 
+/// Whether `epoch` precedes the epoch the bridge described by `status` was
+/// enabled at, meaning no validator set update proof can exist for it yet.
+/// A disabled bridge, or one enabled at genesis, never gates on epoch.
+fn is_epoch_before_bridge_enabled(
+    status: &EthBridgeStatus,
+    epoch: Epoch,
+) -> bool {
+    matches!(
+        status,
+        EthBridgeStatus::Enabled(EthBridgeEnabled::AtEpoch(enabled_at))
+            if epoch < *enabled_at
+    )
+}
+
+#[cfg(test)]
+mod signed_valset_upd_proof_tests {
+    use super::*;
+
+    #[test]
+    fn epoch_before_enabled_at_epoch_is_gated() {
+        let status =
+            EthBridgeStatus::Enabled(EthBridgeEnabled::AtEpoch(Epoch(10)));
+        assert!(is_epoch_before_bridge_enabled(&status, Epoch(9)));
+        assert!(!is_epoch_before_bridge_enabled(&status, Epoch(10)));
+        assert!(!is_epoch_before_bridge_enabled(&status, Epoch(11)));
+    }
+
+    #[test]
+    fn enabled_at_genesis_is_never_gated() {
+        let status = EthBridgeStatus::Enabled(EthBridgeEnabled::AtGenesis);
+        assert!(!is_epoch_before_bridge_enabled(&status, Epoch(0)));
+        assert!(!is_epoch_before_bridge_enabled(&status, Epoch(100)));
+    }
+
+    #[test]
+    fn disabled_bridge_is_never_gated_by_epoch() {
+        let status = EthBridgeStatus::Disabled;
+        assert!(!is_epoch_before_bridge_enabled(&status, Epoch(0)));
+        assert!(!is_epoch_before_bridge_enabled(&status, Epoch(100)));
+    }
+}
+
 pub trait EthBridgeQueries {
-    /// Check if the bridge is disabled, enabled, or
+    /// Check if the bridge to `instance` is disabled, enabled, or
     /// scheduled to be enabled at a specified epoch.
-    fn check_bridge_status(&self) -> EthBridgeStatus;
+    fn check_bridge_status(&self, instance: BridgeInstanceId)
+    -> EthBridgeStatus;
 
-    /// Returns a boolean indicating whether the bridge
+    /// Returns a boolean indicating whether the bridge to `instance`
     /// is currently active.
-    fn is_bridge_active(&self) -> bool;
+    fn is_bridge_active(&self, instance: BridgeInstanceId) -> bool;
 
     /// Fetch the first [`BlockHeight`] of the last [`Epoch`]
     /// committed to storage.
     fn get_epoch_start_height(&self) -> BlockHeight;
 
-    /// Get the latest nonce for the Ethereum bridge
-    /// pool.
-    fn get_bridge_pool_nonce(&self) -> Uint;
+    /// Get the latest nonce for the Ethereum bridge pool of `instance`.
+    ///
+    /// An instance only reads back its own nonce once
+    /// [`write_bridge_pool_nonce_override`] has been called for it; see
+    /// the write-path note on [`write_eth_bridge_status`].
+    fn get_bridge_pool_nonce(&self, instance: BridgeInstanceId) -> Uint;
 
-    /// Get the nonce at a particular block height.
-    fn get_bridge_pool_nonce_at_height(&self, height: BlockHeight) -> Uint;
+    /// Get the nonce of `instance`'s bridge pool at a particular block
+    /// height. See [`Self::get_bridge_pool_nonce`].
+    fn get_bridge_pool_nonce_at_height(
+        &self,
+        instance: BridgeInstanceId,
+        height: BlockHeight,
+    ) -> Uint;
 
-    /// Get the latest root of the Ethereum bridge
-    /// pool Merkle tree.
-    fn get_bridge_pool_root(&self) -> KeccakHash;
+    /// Get the latest root of `instance`'s Ethereum bridge pool Merkle
+    /// tree.
+    ///
+    /// Checks [`bridge_pool_root_key`] for a per-instance override first,
+    /// falling back to the single shared root of the underlying
+    /// [`StoreType::BridgePool`] sub-tree.
+    ///
+    /// An instance only reads back its own root once
+    /// [`write_bridge_pool_root_override`] has been called for it; see the
+    /// write-path note on [`write_eth_bridge_status`].
+    fn get_bridge_pool_root(&self, instance: BridgeInstanceId) -> KeccakHash;
 
     /// Get a quorum of validator signatures over
     /// the concatenation of the latest bridge pool
-    /// root and nonce.
+    /// root and nonce, for `instance`'s bridge pool.
     ///
     /// No value exists when the bridge if first
     /// started.
+    ///
+    /// An instance only reads back its own signed root once
+    /// [`write_signed_bridge_pool_root`] has been called for it; see the
+    /// write-path note on [`write_eth_bridge_status`].
     fn get_signed_bridge_pool_root(
         &self,
+        instance: BridgeInstanceId,
     ) -> Option<EthereumProof<(KeccakHash, Uint)>>;
 
-    /// Get the root of the Ethereum bridge
-    /// pool Merkle tree at a given height.
-    fn get_bridge_pool_root_at_height(&self, height: BlockHeight)
-    -> KeccakHash;
+    /// Get the root of `instance`'s Ethereum bridge pool Merkle tree at a
+    /// given height.
+    ///
+    /// NOTE: see [`Self::get_bridge_pool_root`] - this checks
+    /// [`bridge_pool_root_key`] at `height` first, falling back to the
+    /// shared root until [`write_bridge_pool_root_override`] has been
+    /// called for `instance`.
+    fn get_bridge_pool_root_at_height(
+        &self,
+        instance: BridgeInstanceId,
+        height: BlockHeight,
+    ) -> KeccakHash;
+
+    /// Generate a Merkle inclusion proof that the transfer identified by
+    /// `transfer_hash` was present in `instance`'s Bridge pool at `height`,
+    /// bundled with the signed pool root and nonce so a relayer can submit
+    /// and withdraw the transfer on Ethereum without any further queries.
+    ///
+    /// `height` must be the chain's current tip: [`Self::get_signed_bridge_pool_root`]
+    /// only ever has the *latest* signed root and nonce to bundle, so a
+    /// proof built against any earlier height would not reconstruct to the
+    /// root it ships with, and would be unverifiable on the Ethereum side.
+    /// Returns `None` for any other `height`, if no such transfer is
+    /// found in the pool's Merkle tree at the (current) height, or if no
+    /// signed root is stored for `instance`.
+    fn get_bridge_pool_inclusion_proof(
+        &self,
+        instance: BridgeInstanceId,
+        transfer_hash: KeccakHash,
+        height: BlockHeight,
+    ) -> Option<BridgePoolProof>;
+
+    /// Check whether the Ethereum beacon block identified by `block_root`
+    /// has been proven final at `slot`, according to the Altair light
+    /// client state tracked in storage. Ethereum event processing should
+    /// be gated on this, rather than trusting relayer-reported events.
+    ///
+    /// Only the latest stored finalized header is recognised: this returns
+    /// `true` when `block_root` is that header's root and `slot` is no
+    /// later than its slot. Blocks finalized earlier are not tracked, so
+    /// their roots yield `false`.
+    ///
+    /// [`apply_and_store_light_client_update`] is the write side that
+    /// advances the stored state this checks; see the write-path note on
+    /// [`write_eth_bridge_status`] for the bootstrapping and call-site
+    /// work it is still waiting on, without which this always returns
+    /// `false` and no Ethereum event is actually gated on finality yet.
+    fn verify_eth_finality(&self, block_root: KeccakHash, slot: u64) -> bool;
 
     /// Determines if it is possible to send a validator set update vote
     /// extension at the provided [`BlockHeight`] in [`SendValsetUpd`].
@@ -128,14 +861,62 @@ pub trait EthBridgeQueries {
     }
 
     /// Extension of [`Self::get_active_validators`], which additionally returns
-    /// all Ethereum addresses of some validator.
+    /// all Ethereum addresses of some validator, for `instance`'s bridge.
+    ///
+    /// Checks [`eth_hot_key_override_key`]/[`eth_cold_key_override_key`]
+    /// for a per-instance override of each validator's hot/cold key
+    /// first, falling back to the shared validator-to-Ethereum-address
+    /// mapping.
+    ///
+    /// An instance only reads back its own hot/cold keys for a validator
+    /// once [`write_eth_hot_key_override`]/[`write_eth_cold_key_override`]
+    /// have been called for it; see the write-path note on
+    /// [`write_eth_bridge_status`].
+    ///
+    /// Results for the current epoch are cached per `cache_scope`; see
+    /// [`EthBridgeCacheScope`] for why that must be a token the caller
+    /// mints once per long-lived `Storage`, not inferred from `self`.
     fn get_active_eth_addresses<'db>(
         &'db self,
+        cache_scope: EthBridgeCacheScope,
+        instance: BridgeInstanceId,
         epoch: Option<Epoch>,
     ) -> Box<dyn Iterator<Item = (EthAddrBook, Address, token::Amount)> + 'db>;
 
-    /// Query the active [`ValidatorSetArgs`] at the given [`Epoch`].
-    fn get_validator_set_args(&self, epoch: Option<Epoch>) -> ValidatorSetArgs;
+    /// Query the active [`ValidatorSetArgs`] for `instance`'s bridge, at
+    /// the given [`Epoch`].
+    ///
+    /// Validator sets may differ per bridge instance, since a validator's
+    /// hot/cold key mapping can be distinct for each target chain.
+    ///
+    /// Results for the current epoch are cached per `cache_scope`; see
+    /// [`EthBridgeCacheScope`] for why that must be a token the caller
+    /// mints once per long-lived `Storage`, not inferred from `self`.
+    fn get_validator_set_args(
+        &self,
+        cache_scope: EthBridgeCacheScope,
+        instance: BridgeInstanceId,
+        epoch: Option<Epoch>,
+    ) -> ValidatorSetArgs;
+
+    /// Get a quorum of validator signatures over the hash of the
+    /// validator set that was active at `epoch`, for `instance`'s bridge,
+    /// so that a relayer who missed the live window can still submit a
+    /// historical validator set update.
+    ///
+    /// Returns `None` if `epoch` precedes the epoch the bridge was
+    /// enabled at.
+    ///
+    /// [`write_signed_valset_upd_proof`] is the write side that
+    /// populates [`signed_valset_upd_key`]; see the write-path note on
+    /// [`write_eth_bridge_status`] for the caller it is still waiting
+    /// on, without which this always falls through to `None` for every
+    /// epoch.
+    fn get_signed_valset_upd_proof(
+        &self,
+        instance: BridgeInstanceId,
+        epoch: Epoch,
+    ) -> Option<EthereumProof<ValidatorSetArgs>>;
 }
 
 impl<D, H> EthBridgeQueries for Storage<D, H>
@@ -143,21 +924,19 @@ where
     D: storage::DB + for<'iter> storage::DBIter<'iter>,
     H: storage::StorageHasher,
 {
-    fn check_bridge_status(&self) -> EthBridgeStatus {
-        BorshDeserialize::try_from_slice(
-            self.read(&active_key())
-                .expect(
-                    "Reading the Ethereum bridge active key shouldn't fail.",
-                )
-                .0
-                .expect("The Ethereum bridge active key should be in storage")
-                .as_slice(),
-        )
-        .expect("Deserializing the Ethereum bridge active key shouldn't fail.")
+    fn check_bridge_status(
+        &self,
+        instance: BridgeInstanceId,
+    ) -> EthBridgeStatus {
+        let bytes = read_scoped_or_legacy(self, instance, &active_key())
+            .expect("The Ethereum bridge active key should be in storage");
+        BorshDeserialize::try_from_slice(bytes.as_slice())
+            .expect("Deserializing the Ethereum bridge active key shouldn't fail.")
     }
 
-    fn is_bridge_active(&self) -> bool {
-        if let EthBridgeStatus::Enabled(enabled_at) = self.check_bridge_status()
+    fn is_bridge_active(&self, instance: BridgeInstanceId) -> bool {
+        if let EthBridgeStatus::Enabled(enabled_at) =
+            self.check_bridge_status(instance)
         {
             match enabled_at {
                 EthBridgeEnabled::AtGenesis => true,
@@ -189,60 +968,158 @@ where
             .expect("The block height of the current epoch should be known")
     }
 
-    fn get_bridge_pool_nonce(&self) -> Uint {
-        Uint::try_from_slice(
-            &self
-                .read(&get_nonce_key())
-                .expect("Reading Bridge pool nonce shouldn't fail.")
-                .0
-                .expect("Reading Bridge pool nonce shouldn't fail."),
-        )
-        .expect("Deserializing the nonce from storage should not fail.")
+    fn get_bridge_pool_nonce(&self, instance: BridgeInstanceId) -> Uint {
+        let bytes = read_scoped_or_legacy(self, instance, &get_nonce_key())
+            .expect("Reading Bridge pool nonce shouldn't fail.");
+        Uint::try_from_slice(&bytes)
+            .expect("Deserializing the nonce from storage should not fail.")
     }
 
-    fn get_bridge_pool_nonce_at_height(&self, height: BlockHeight) -> Uint {
-        Uint::try_from_slice(
-            &self
-                .db
-                .read_subspace_val_with_height(
-                    &get_nonce_key(),
-                    height,
-                    self.last_height,
-                )
-                .expect("Reading signed Bridge pool nonce shouldn't fail.")
-                .expect("Reading signed Bridge pool nonce shouldn't fail."),
+    fn get_bridge_pool_nonce_at_height(
+        &self,
+        instance: BridgeInstanceId,
+        height: BlockHeight,
+    ) -> Uint {
+        let bytes = read_scoped_or_legacy_at_height(
+            self,
+            instance,
+            &get_nonce_key(),
+            height,
         )
-        .expect("Deserializing the signed nonce from storage should not fail.")
+        .expect("Reading signed Bridge pool nonce shouldn't fail.");
+        Uint::try_from_slice(&bytes)
+            .expect("Deserializing the signed nonce from storage should not fail.")
     }
 
-    fn get_bridge_pool_root(&self) -> KeccakHash {
-        self.block.tree.sub_root(&StoreType::BridgePool).into()
+    fn get_bridge_pool_root(&self, instance: BridgeInstanceId) -> KeccakHash {
+        self.read(&bridge_pool_root_key(instance))
+            .expect("Reading a per-instance bridge pool root shouldn't fail.")
+            .0
+            .map(|bytes| {
+                BorshDeserialize::try_from_slice(&bytes).expect(
+                    "Deserializing a per-instance bridge pool root from \
+                     storage should not fail.",
+                )
+            })
+            // see the doc on `Self::get_bridge_pool_root`: nothing writes
+            // a per-instance root yet, so every instance falls back to the
+            // one shared sub-tree root
+            .unwrap_or_else(|| {
+                self.block.tree.sub_root(&StoreType::BridgePool).into()
+            })
     }
 
     fn get_signed_bridge_pool_root(
         &self,
+        instance: BridgeInstanceId,
     ) -> Option<EthereumProof<(KeccakHash, Uint)>> {
-        self.read(&get_signed_root_key())
-            .expect("Reading signed Bridge pool root shouldn't fail.")
-            .0
-            .map(|bytes| {
+        read_scoped_or_legacy(self, instance, &get_signed_root_key()).map(
+            |bytes| {
                 BorshDeserialize::try_from_slice(&bytes).expect(
                     "Deserializing the signed bridge pool root from storage \
                      should not fail.",
                 )
-            })
+            },
+        )
     }
 
     fn get_bridge_pool_root_at_height(
         &self,
+        instance: BridgeInstanceId,
         height: BlockHeight,
     ) -> KeccakHash {
         self.db
+            .read_subspace_val_with_height(
+                &bridge_pool_root_key(instance),
+                height,
+                self.last_height,
+            )
+            .expect("Reading a per-instance bridge pool root shouldn't fail.")
+            .map(|bytes| {
+                BorshDeserialize::try_from_slice(&bytes).expect(
+                    "Deserializing a per-instance bridge pool root from \
+                     storage should not fail.",
+                )
+            })
+            // see the NOTE on `Self::get_bridge_pool_root_at_height`:
+            // nothing writes a per-instance root yet, so every instance
+            // falls back to the one shared sub-tree root at this height
+            .unwrap_or_else(|| {
+                self.db
+                    .read_merkle_tree_stores(height)
+                    .expect("We should always be able to read the database")
+                    .expect(
+                        "Every root should correspond to an existing block \
+                         height",
+                    )
+                    .get_root(StoreType::BridgePool)
+                    .into()
+            })
+    }
+
+    fn get_bridge_pool_inclusion_proof(
+        &self,
+        instance: BridgeInstanceId,
+        transfer_hash: KeccakHash,
+        height: BlockHeight,
+    ) -> Option<BridgePoolProof> {
+        // see the doc comment on the trait method: `signed_root` below is
+        // always the *latest* signed root/nonce, so a proof built against
+        // any height behind the tip would not reconstruct to it
+        if !inclusion_proof_height_is_supported(height, self.last_height) {
+            return None;
+        }
+
+        let stores = self
+            .db
             .read_merkle_tree_stores(height)
             .expect("We should always be able to read the database")
-            .expect("Every root should correspond to an existing block height")
-            .get_root(StoreType::BridgePool)
-            .into()
+            .expect(
+                "Every root should correspond to an existing block height",
+            );
+        // NOTE: `read_merkle_tree_stores` returns the stored sub-trees,
+        // not a tree we can walk for sibling paths, so the tree is
+        // reconstructed here from those stores and then asked for a
+        // membership proof of this single leaf.
+        let tree = storage::MerkleTree::<H>::new(stores).expect(
+            "Reconstructing the Merkle tree from the stored sub-trees \
+             shouldn't fail",
+        );
+        let membership_proof = tree
+            .get_sub_tree_existence_proof(
+                StoreType::BridgePool,
+                std::slice::from_ref(&transfer_hash),
+            )
+            .ok()?;
+        let leaf_index = membership_proof.leaf_index();
+        let sibling_hashes = membership_proof.siblings().to_vec();
+
+        let signed_root = self.get_signed_bridge_pool_root(instance)?;
+
+        Some(BridgePoolProof {
+            leaf_index,
+            sibling_hashes,
+            signed_root,
+        })
+    }
+
+    fn verify_eth_finality(&self, block_root: KeccakHash, slot: u64) -> bool {
+        let Some(bytes) = self
+            .read(&light_client_key())
+            .expect("Reading the Ethereum light client state shouldn't fail.")
+            .0
+        else {
+            // the light client hasn't observed any update yet
+            return false;
+        };
+        let store: LightClientStore = BorshDeserialize::try_from_slice(&bytes)
+            .expect(
+                "Deserializing the Ethereum light client state shouldn't \
+                 fail.",
+            );
+
+        slot <= store.finalized_header.slot
+            && block_root == store.finalized_header.root()
     }
 
     #[cfg(feature = "abcipp")]
@@ -296,29 +1173,84 @@ where
             .and_then(|epk| epk.get(epoch).and_then(|pk| pk.try_into().ok()))
     }
 
-    #[inline]
     fn get_active_eth_addresses<'db>(
         &'db self,
+        cache_scope: EthBridgeCacheScope,
+        instance: BridgeInstanceId,
         epoch: Option<Epoch>,
     ) -> Box<dyn Iterator<Item = (EthAddrBook, Address, token::Amount)> + 'db>
     {
-        let epoch = epoch.unwrap_or_else(|| self.get_current_epoch().0);
-        Box::new(self.get_active_validators(Some(epoch)).into_iter().map(
-            move |validator| {
+        let current_epoch = self.get_current_epoch().0;
+        invalidate_stale_eth_bridge_caches(cache_scope, current_epoch);
+        let epoch = epoch.unwrap_or(current_epoch);
+        let instance_key = (cache_scope, instance);
+
+        if let Some(cached) = active_addresses_cache()
+            .lock()
+            .unwrap()
+            .get_mut(&instance_key)
+            .and_then(|lru| lru.get(&epoch))
+        {
+            let cached = Arc::clone(cached);
+            return Box::new((*cached).clone().into_iter());
+        }
+
+        let addresses: Vec<_> = self
+            .get_active_validators(Some(epoch))
+            .into_iter()
+            .map(|validator| {
+                // checks the per-instance override key first, falling
+                // back to the shared mapping until
+                // `write_eth_hot_key_override` has been called for this
+                // instance and validator
                 let hot_key_addr = self
-                    .get_ethbridge_from_namada_addr(
+                    .read(&eth_hot_key_override_key(
+                        instance,
                         &validator.address,
-                        Some(epoch),
+                    ))
+                    .expect(
+                        "Reading a per-instance hot key override shouldn't \
+                         fail.",
                     )
+                    .0
+                    .map(|bytes| {
+                        EthAddress::try_from_slice(&bytes).expect(
+                            "Deserializing a hot key override from storage \
+                             should not fail.",
+                        )
+                    })
+                    .or_else(|| {
+                        self.get_ethbridge_from_namada_addr(
+                            &validator.address,
+                            Some(epoch),
+                        )
+                    })
                     .expect(
-                        "All Namada validators should have an Ethereum bridge \
-                         key",
+                        "All Namada validators should have an Ethereum \
+                         bridge key",
                     );
                 let cold_key_addr = self
-                    .get_ethgov_from_namada_addr(
+                    .read(&eth_cold_key_override_key(
+                        instance,
                         &validator.address,
-                        Some(epoch),
+                    ))
+                    .expect(
+                        "Reading a per-instance cold key override \
+                         shouldn't fail.",
                     )
+                    .0
+                    .map(|bytes| {
+                        EthAddress::try_from_slice(&bytes).expect(
+                            "Deserializing a cold key override from \
+                             storage should not fail.",
+                        )
+                    })
+                    .or_else(|| {
+                        self.get_ethgov_from_namada_addr(
+                            &validator.address,
+                            Some(epoch),
+                        )
+                    })
                     .expect(
                         "All Namada validators should have an Ethereum \
                          governance key",
@@ -332,15 +1264,43 @@ where
                     validator.address,
                     validator.bonded_stake.into(),
                 )
-            },
-        ))
+            })
+            .collect();
+
+        let addresses = Arc::new(addresses);
+        active_addresses_cache()
+            .lock()
+            .unwrap()
+            .entry(instance_key)
+            .or_insert_with(|| {
+                LruCache::new(NonZeroUsize::new(EPOCH_CACHE_SIZE).unwrap())
+            })
+            .put(epoch, Arc::clone(&addresses));
+        Box::new((*addresses).clone().into_iter())
     }
 
-    fn get_validator_set_args(&self, epoch: Option<Epoch>) -> ValidatorSetArgs {
-        let epoch = epoch.unwrap_or_else(|| self.get_current_epoch().0);
+    fn get_validator_set_args(
+        &self,
+        cache_scope: EthBridgeCacheScope,
+        instance: BridgeInstanceId,
+        epoch: Option<Epoch>,
+    ) -> ValidatorSetArgs {
+        let current_epoch = self.get_current_epoch().0;
+        invalidate_stale_eth_bridge_caches(cache_scope, current_epoch);
+        let epoch = epoch.unwrap_or(current_epoch);
+        let instance_key = (cache_scope, instance);
+
+        if let Some(cached_args) = valset_cache()
+            .lock()
+            .unwrap()
+            .get_mut(&instance_key)
+            .and_then(|lru| lru.get(&epoch))
+        {
+            return (**cached_args).clone();
+        }
 
         let voting_powers_map: VotingPowersMap = self
-            .get_active_eth_addresses(Some(epoch))
+            .get_active_eth_addresses(cache_scope, instance, Some(epoch))
             .map(|(addr_book, _, power)| (addr_book, power))
             .collect();
 
@@ -357,10 +1317,50 @@ where
             })
             .unzip();
 
-        ValidatorSetArgs {
+        let args = Arc::new(ValidatorSetArgs {
             epoch,
             validators,
             voting_powers,
+        });
+        valset_cache()
+            .lock()
+            .unwrap()
+            .entry(instance_key)
+            .or_insert_with(|| {
+                LruCache::new(NonZeroUsize::new(EPOCH_CACHE_SIZE).unwrap())
+            })
+            .put(epoch, Arc::clone(&args));
+        (*args).clone()
+    }
+
+    fn get_signed_valset_upd_proof(
+        &self,
+        instance: BridgeInstanceId,
+        epoch: Epoch,
+    ) -> Option<EthereumProof<ValidatorSetArgs>> {
+        if is_epoch_before_bridge_enabled(
+            &self.check_bridge_status(instance),
+            epoch,
+        ) {
+            return None;
         }
+
+        // see the doc on `Self::get_signed_valset_upd_proof`: until the
+        // live quorum tally calls `write_signed_valset_upd_proof`, this
+        // key is never populated and the method returns `None` for
+        // every epoch, regardless of whether a quorum was actually
+        // reached live
+        self.read(&instance.scope(signed_valset_upd_key(epoch)))
+            .expect(
+                "Reading the signed validator set update proof shouldn't \
+                 fail.",
+            )
+            .0
+            .map(|bytes| {
+                BorshDeserialize::try_from_slice(&bytes).expect(
+                    "Deserializing the signed validator set update proof \
+                     from storage should not fail.",
+                )
+            })
     }
 }
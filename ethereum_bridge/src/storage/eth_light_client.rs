@@ -0,0 +1,711 @@
+use blst::min_pk::{AggregatePublicKey, PublicKey, Signature};
+use blst::BLST_ERROR;
+use borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::types::keccak::{keccak_hash, KeccakHash};
+use namada_core::types::storage::{DbKeySeg, Key};
+use sha2::{Digest, Sha256};
+
+/// Number of validators that make up a sync committee, per the Altair
+/// consensus spec.
+pub const SYNC_COMMITTEE_SIZE: usize = 512;
+
+/// Number of epochs a sync committee is valid for before it rotates.
+pub const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: u64 = 256;
+
+/// Number of slots per epoch, per the consensus spec. Needed to convert
+/// a [`BeaconBlockHeader::slot`] into the epoch it falls in before
+/// computing a [`LightClientStore::sync_committee_period`].
+pub const SLOTS_PER_EPOCH: u64 = 32;
+
+/// Domain type for sync committee signatures, per the Altair consensus
+/// spec (`DOMAIN_SYNC_COMMITTEE`).
+const DOMAIN_SYNC_COMMITTEE: [u8; 4] = [0x07, 0x00, 0x00, 0x00];
+
+/// Ciphersuite used to verify sync committee BLS signatures, as
+/// specified by the Altair consensus spec for signatures over beacon
+/// chain messages.
+const BLS_DST: &[u8] =
+    b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Generalized index of `BeaconState.finalized_checkpoint.root` in the
+/// Altair beacon state Merkle tree, per the consensus spec's
+/// `FINALIZED_ROOT_INDEX`. Fixed by the (also fixed) shape of
+/// `BeaconState`, not something a proof can choose.
+const FINALIZED_ROOT_GINDEX: u64 = 105;
+
+/// Generalized index of `BeaconState.next_sync_committee` in the Altair
+/// beacon state Merkle tree, per the consensus spec's
+/// `NEXT_SYNC_COMMITTEE_INDEX`.
+const NEXT_SYNC_COMMITTEE_GINDEX: u64 = 55;
+
+/// The storage key under which the Ethereum light client's state is kept,
+/// sitting alongside [`crate::storage::eth_bridge_queries::active_key`].
+pub fn light_client_key() -> Key {
+    Key::parse("eth_bridge/light_client")
+        .expect("Constructing the light client storage key shouldn't fail")
+        .push(&DbKeySeg::StringSeg("state".into()))
+        .expect("Pushing the light client state segment shouldn't fail")
+}
+
+/// A compressed BLS12-381 public key.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize,
+)]
+pub struct BlsPublicKey(pub [u8; 48]);
+
+/// A compressed BLS12-381 signature.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize,
+)]
+pub struct BlsSignature(pub [u8; 96]);
+
+/// An ordered Merkle branch, from a leaf up to the root of a beacon
+/// state's Merkle tree. The generalized index the branch proves
+/// inclusion at is not carried here: for the two branches Namada
+/// verifies, it is a protocol-level constant ([`FINALIZED_ROOT_GINDEX`]
+/// or [`NEXT_SYNC_COMMITTEE_GINDEX`]), fixed by `BeaconState`'s (also
+/// fixed) shape rather than chosen per proof.
+pub type MerkleBranch = Vec<KeccakHash>;
+
+/// A minimal beacon chain block header, per the consensus spec.
+#[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
+pub struct BeaconBlockHeader {
+    pub slot: u64,
+    pub proposer_index: u64,
+    pub parent_root: KeccakHash,
+    pub state_root: KeccakHash,
+    pub body_root: KeccakHash,
+}
+
+impl BeaconBlockHeader {
+    /// The header's own root, i.e. the value that a child header's
+    /// `parent_root` should match. This is the SSZ `hash_tree_root` of
+    /// the `BeaconBlockHeader` container: a depth-3 Merkle tree (padded
+    /// from 5 fields to 8 leaves) of each field's own root, hashed with
+    /// SHA256 as the consensus spec requires (not the `KeccakHash`
+    /// container's namesake Keccak - we reuse it here purely as a
+    /// generic 32-byte hash holder).
+    pub fn root(&self) -> KeccakHash {
+        let leaves = [
+            pack_uint64(self.slot),
+            pack_uint64(self.proposer_index),
+            self.parent_root.0,
+            self.state_root.0,
+            self.body_root.0,
+        ];
+        KeccakHash(merkleize(&leaves))
+    }
+}
+
+/// SSZ-pack a `uint64` into a zero-padded 32-byte Merkle leaf.
+fn pack_uint64(value: u64) -> [u8; 32] {
+    let mut chunk = [0u8; 32];
+    chunk[..8].copy_from_slice(&value.to_le_bytes());
+    chunk
+}
+
+/// The SSZ `hash_tree_root` of a `Bytes48` (e.g. a compressed BLS
+/// pubkey): pack into 32-byte chunks (48 bytes needs 2, zero-padded) and
+/// merkleize.
+fn bytes48_root(bytes: &[u8; 48]) -> [u8; 32] {
+    let mut second_chunk = [0u8; 32];
+    second_chunk[..16].copy_from_slice(&bytes[32..]);
+    let chunks = [
+        bytes[..32].try_into().expect("slice is exactly 32 bytes"),
+        second_chunk,
+    ];
+    merkleize(&chunks)
+}
+
+/// SHA256 of two concatenated 32-byte hashes, the inner node hash used
+/// throughout SSZ Merkleization.
+fn sha256_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// The all-zero hash of an empty subtree `depth` levels deep, per SSZ's
+/// `zero_hashes`, used to pad `leaves` up to a power of two in
+/// [`merkleize`].
+fn zero_hash(depth: u32) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    for _ in 0..depth {
+        hash = sha256_pair(&hash, &hash);
+    }
+    hash
+}
+
+/// SSZ `merkleize`: build a binary Merkle tree over `leaves`, padding
+/// with [`zero_hash`]es up to the next power of two, and return the
+/// root.
+fn merkleize(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return zero_hash(0);
+    }
+    let mut size = 1usize;
+    let mut depth = 0u32;
+    while size < leaves.len() {
+        size *= 2;
+        depth += 1;
+    }
+
+    let mut level = leaves.to_vec();
+    level.resize(size, zero_hash(0));
+    for _ in 0..depth {
+        level = level
+            .chunks(2)
+            .map(|pair| sha256_pair(&pair[0], &pair[1]))
+            .collect();
+    }
+    level[0]
+}
+
+/// A committee of validators responsible for attesting to, and
+/// participating in sync aggregates over, a given sync committee period.
+#[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
+pub struct SyncCommittee {
+    pub pubkeys: Vec<BlsPublicKey>,
+    pub aggregate_pubkey: BlsPublicKey,
+}
+
+/// An aggregate BLS signature produced by the subset of `current_sync_
+/// committee` members that participated in attesting to a block, along
+/// with a bitfield recording which members participated.
+#[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
+pub struct SyncAggregate {
+    /// One bit per member of the 512-validator sync committee.
+    pub sync_committee_bits: Vec<bool>,
+    pub sync_committee_signature: BlsSignature,
+}
+
+impl SyncAggregate {
+    /// Number of participating validators, according to
+    /// [`Self::sync_committee_bits`].
+    fn participation(&self) -> usize {
+        self.sync_committee_bits.iter().filter(|bit| **bit).count()
+    }
+
+    /// An update can only be applied if at least two thirds of the sync
+    /// committee participated in the aggregate signature.
+    fn has_supermajority(&self) -> bool {
+        self.participation() * 3 >= SYNC_COMMITTEE_SIZE * 2
+    }
+}
+
+/// An update to the Ethereum light client's view of consensus, following
+/// the Altair light client sync protocol.
+#[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
+pub struct LightClientUpdate {
+    /// Beacon header attested to by [`Self::sync_aggregate`].
+    pub attested_header: BeaconBlockHeader,
+    /// Beacon header finalized as of the attested header's state.
+    pub finalized_header: BeaconBlockHeader,
+    /// Merkle branch proving `finalized_header` is the finalized
+    /// checkpoint committed to in `attested_header`'s state root.
+    pub finality_branch: MerkleBranch,
+    /// The next sync committee, and a Merkle branch proving it is
+    /// committed to in the attested header's state root, when rotating
+    /// into a new sync committee period.
+    pub next_sync_committee: Option<(SyncCommittee, MerkleBranch)>,
+    /// Aggregate signature of `current_sync_committee` members over
+    /// `attested_header`.
+    pub sync_aggregate: SyncAggregate,
+}
+
+/// Light client state tracked in storage: the latest finalized Ethereum
+/// beacon header, and the sync committee currently responsible for
+/// attesting to new headers.
+#[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
+pub struct LightClientStore {
+    pub finalized_header: BeaconBlockHeader,
+    pub current_sync_committee: SyncCommittee,
+    pub next_sync_committee: Option<SyncCommittee>,
+}
+
+impl LightClientStore {
+    /// Sync committee period a given `slot` falls into.
+    fn sync_committee_period(slot: u64) -> u64 {
+        let epoch = slot / SLOTS_PER_EPOCH;
+        epoch / EPOCHS_PER_SYNC_COMMITTEE_PERIOD
+    }
+
+    /// Apply a [`LightClientUpdate`] to this light client's state,
+    /// following the Altair light client update rule:
+    ///
+    /// 1. at least 2/3 of the current sync committee must have
+    ///    participated in the aggregate signature;
+    /// 2. the finality Merkle branch must prove `finalized_header` is
+    ///    committed to in the attested header's state root;
+    /// 3. when present, the next-sync-committee Merkle branch must prove
+    ///    it is committed to in the attested header's state root;
+    /// 4. the aggregate BLS signature must verify against the
+    ///    aggregated public keys of the participating members of
+    ///    `current_sync_committee`, under the domain derived from the
+    ///    fork version and genesis validators root.
+    ///
+    /// On success, [`Self::finalized_header`] advances to the update's
+    /// finalized header, and at sync committee period boundaries,
+    /// [`Self::current_sync_committee`] rotates to the stored next sync
+    /// committee.
+    pub fn apply_update(
+        &mut self,
+        update: &LightClientUpdate,
+        fork_version: [u8; 4],
+        genesis_validators_root: KeccakHash,
+    ) -> bool {
+        if !update.sync_aggregate.has_supermajority() {
+            return false;
+        }
+        if !verify_merkle_branch(
+            &update.finality_branch,
+            &update.finalized_header.root(),
+            FINALIZED_ROOT_GINDEX,
+            &update.attested_header.state_root,
+        ) {
+            return false;
+        }
+        if let Some((next_committee, branch)) = &update.next_sync_committee {
+            if !verify_merkle_branch(
+                branch,
+                &committee_root(next_committee),
+                NEXT_SYNC_COMMITTEE_GINDEX,
+                &update.attested_header.state_root,
+            ) {
+                return false;
+            }
+        }
+        if !verify_sync_aggregate_signature(
+            &update.sync_aggregate,
+            &self.current_sync_committee,
+            &update.attested_header,
+            fork_version,
+            genesis_validators_root,
+        ) {
+            return false;
+        }
+
+        let prev_period =
+            Self::sync_committee_period(self.finalized_header.slot);
+        let new_period =
+            Self::sync_committee_period(update.finalized_header.slot);
+
+        self.finalized_header = update.finalized_header.clone();
+        if let Some((next_committee, _)) = &update.next_sync_committee {
+            self.next_sync_committee = Some(next_committee.clone());
+        }
+        if new_period > prev_period {
+            if let Some(next_committee) = self.next_sync_committee.take() {
+                self.current_sync_committee = next_committee;
+            }
+        }
+
+        true
+    }
+}
+
+/// Compute the SSZ `hash_tree_root` of a [`SyncCommittee`], as committed
+/// to in a beacon state's `next_sync_committee` field: the container's
+/// two fields are `pubkeys: Vector[BLSPubkey, SYNC_COMMITTEE_SIZE]`
+/// (merkleized per-pubkey roots) and `aggregate_pubkey: BLSPubkey`.
+fn committee_root(committee: &SyncCommittee) -> KeccakHash {
+    if committee.pubkeys.len() != SYNC_COMMITTEE_SIZE {
+        // not a well-formed SSZ `SyncCommittee` - make sure it can never
+        // accidentally match a real root instead of just failing to
+        // verify
+        return KeccakHash([0u8; 32]);
+    }
+    let pubkey_roots: Vec<[u8; 32]> =
+        committee.pubkeys.iter().map(|pk| bytes48_root(&pk.0)).collect();
+    let pubkeys_root = merkleize(&pubkey_roots);
+    let aggregate_root = bytes48_root(&committee.aggregate_pubkey.0);
+    KeccakHash(merkleize(&[pubkeys_root, aggregate_root]))
+}
+
+/// Depth of the Merkle branch leading to `generalized_index`, i.e.
+/// `floor(log2(generalized_index))`.
+fn merkle_branch_depth(generalized_index: u64) -> u32 {
+    u64::BITS - 1 - generalized_index.leading_zeros()
+}
+
+/// Verify that `branch` proves `leaf` sits at `generalized_index` in the
+/// tree committed to by `root`, per the consensus spec's
+/// `is_valid_merkle_branch`. Each bit of `generalized_index` (from the
+/// leaf upward) says whether `leaf`/the running hash is the left or
+/// right child at that level, which determines sibling ordering -
+/// unlike a plain hash-chain, a real SSZ proof's siblings aren't always
+/// on the same side.
+fn verify_merkle_branch(
+    branch: &MerkleBranch,
+    leaf: &KeccakHash,
+    generalized_index: u64,
+    root: &KeccakHash,
+) -> bool {
+    let depth = merkle_branch_depth(generalized_index);
+    if branch.len() as u32 != depth {
+        return false;
+    }
+    let mut computed = leaf.0;
+    for (i, sibling) in branch.iter().enumerate() {
+        computed = if (generalized_index >> i) & 1 == 1 {
+            sha256_pair(&sibling.0, &computed)
+        } else {
+            sha256_pair(&computed, &sibling.0)
+        };
+    }
+    computed == root.0
+}
+
+/// The SSZ `hash_tree_root` of a `ForkData` container (`{current_version:
+/// Version, genesis_validators_root: Root}`), per the consensus spec's
+/// `compute_fork_data_root`: the 4-byte version is SSZ-packed into its own
+/// zero-padded 32-byte chunk, then the two chunks are merkleized with
+/// SHA256 - not a Keccak hash of the raw concatenated bytes, which a real
+/// beacon chain signer never computes.
+fn compute_fork_data_root(
+    fork_version: [u8; 4],
+    genesis_validators_root: KeccakHash,
+) -> [u8; 32] {
+    let mut version_chunk = [0u8; 32];
+    version_chunk[..4].copy_from_slice(&fork_version);
+    sha256_pair(&version_chunk, &genesis_validators_root.0)
+}
+
+/// Domain derived from `fork_version` and `genesis_validators_root`,
+/// following the consensus spec's `compute_domain`: the first 4 bytes
+/// identify the signature's purpose (sync committee messages), and the
+/// remaining 28 come from [`compute_fork_data_root`], so a signature
+/// produced under one fork or chain can never verify under another.
+fn compute_domain(
+    fork_version: [u8; 4],
+    genesis_validators_root: KeccakHash,
+) -> [u8; 32] {
+    let fork_data_root =
+        compute_fork_data_root(fork_version, genesis_validators_root);
+
+    let mut domain = [0u8; 32];
+    domain[..4].copy_from_slice(&DOMAIN_SYNC_COMMITTEE);
+    domain[4..].copy_from_slice(&fork_data_root[..28]);
+    domain
+}
+
+/// The message actually signed by sync committee members: the SSZ
+/// `hash_tree_root` of a `SigningData` container (`{object_root: Root,
+/// domain: Domain}`), per the consensus spec's `compute_signing_root` -
+/// `header`'s own root paired with the signing `domain` and merkleized
+/// with SHA256, not a Keccak hash of the raw concatenated bytes.
+fn compute_signing_root(
+    header: &BeaconBlockHeader,
+    domain: [u8; 32],
+) -> KeccakHash {
+    KeccakHash(sha256_pair(&header.root().0, &domain))
+}
+
+/// Verify the aggregate BLS signature of `sync_aggregate` was produced by
+/// the participating members of `committee` over `header`, under the
+/// domain derived from `fork_version` and `genesis_validators_root`.
+fn verify_sync_aggregate_signature(
+    sync_aggregate: &SyncAggregate,
+    committee: &SyncCommittee,
+    header: &BeaconBlockHeader,
+    fork_version: [u8; 4],
+    genesis_validators_root: KeccakHash,
+) -> bool {
+    if sync_aggregate.sync_committee_bits.len() != committee.pubkeys.len() {
+        return false;
+    }
+
+    let participating_pubkeys: Vec<PublicKey> = match committee
+        .pubkeys
+        .iter()
+        .zip(&sync_aggregate.sync_committee_bits)
+        .filter(|(_, &participated)| participated)
+        .map(|(pubkey, _)| PublicKey::key_validate(&pubkey.0))
+        .collect()
+    {
+        Ok(pubkeys) => pubkeys,
+        Err(_) => return false,
+    };
+    if participating_pubkeys.is_empty() {
+        // an aggregate with no participants can't be meaningfully
+        // verified, and `has_supermajority` should have already
+        // rejected this update before we ever get here
+        return false;
+    }
+
+    let pubkey_refs: Vec<&PublicKey> = participating_pubkeys.iter().collect();
+    let aggregate_pubkey =
+        match AggregatePublicKey::aggregate(&pubkey_refs, true) {
+            Ok(aggregate) => aggregate.to_public_key(),
+            Err(_) => return false,
+        };
+
+    let signature = match Signature::from_bytes(
+        &sync_aggregate.sync_committee_signature.0,
+    ) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+
+    let domain = compute_domain(fork_version, genesis_validators_root);
+    let signing_root = compute_signing_root(header, domain);
+
+    signature.verify(
+        true,
+        signing_root.0.as_slice(),
+        BLS_DST,
+        &[],
+        &aggregate_pubkey,
+        true,
+    ) == BLST_ERROR::BLST_SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use blst::min_pk::SecretKey;
+
+    use super::*;
+
+    fn test_header(slot: u64) -> BeaconBlockHeader {
+        BeaconBlockHeader {
+            slot,
+            proposer_index: 0,
+            parent_root: keccak_hash("parent"),
+            state_root: keccak_hash("state"),
+            body_root: keccak_hash("body"),
+        }
+    }
+
+    fn keypair(seed: u8) -> (SecretKey, BlsPublicKey) {
+        let ikm = [seed; 32];
+        let secret_key = SecretKey::key_gen(&ikm, &[]).unwrap();
+        let public_key = BlsPublicKey(secret_key.sk_to_pk().to_bytes());
+        (secret_key, public_key)
+    }
+
+    fn sign(
+        secret_key: &SecretKey,
+        header: &BeaconBlockHeader,
+        fork_version: [u8; 4],
+        genesis_validators_root: KeccakHash,
+    ) -> BlsSignature {
+        let domain = compute_domain(fork_version, genesis_validators_root);
+        let signing_root = compute_signing_root(header, domain);
+        let signature =
+            secret_key.sign(signing_root.0.as_slice(), BLS_DST, &[]);
+        BlsSignature(signature.to_bytes())
+    }
+
+    #[test]
+    fn sync_committee_period_converts_slot_through_epoch() {
+        // slot 0 is period 0
+        assert_eq!(LightClientStore::sync_committee_period(0), 0);
+        // one slot short of the first period boundary
+        // (256 epochs * 32 slots/epoch - 1)
+        assert_eq!(
+            LightClientStore::sync_committee_period(
+                256 * SLOTS_PER_EPOCH - 1
+            ),
+            0
+        );
+        // exactly at the boundary, the period advances
+        assert_eq!(
+            LightClientStore::sync_committee_period(256 * SLOTS_PER_EPOCH),
+            1
+        );
+        // a slot number that is a multiple of EPOCHS_PER_SYNC_COMMITTEE_
+        // PERIOD, but not of SLOTS_PER_EPOCH * EPOCHS_PER_SYNC_COMMITTEE_
+        // PERIOD, must not be mistaken for a period boundary (this is
+        // exactly the bug of dividing the raw slot instead of the epoch)
+        assert_eq!(LightClientStore::sync_committee_period(256), 0);
+    }
+
+    #[test]
+    fn has_supermajority_threshold() {
+        let below = SyncAggregate {
+            sync_committee_bits: {
+                let mut bits = vec![false; SYNC_COMMITTEE_SIZE];
+                bits[..(SYNC_COMMITTEE_SIZE * 2 / 3 - 1)]
+                    .iter_mut()
+                    .for_each(|bit| *bit = true);
+                bits
+            },
+            sync_committee_signature: BlsSignature([0u8; 96]),
+        };
+        assert!(!below.has_supermajority());
+
+        let at_threshold = SyncAggregate {
+            sync_committee_bits: {
+                let mut bits = vec![false; SYNC_COMMITTEE_SIZE];
+                bits.iter_mut()
+                    .take((SYNC_COMMITTEE_SIZE * 2 + 2) / 3)
+                    .for_each(|bit| *bit = true);
+                bits
+            },
+            sync_committee_signature: BlsSignature([0u8; 96]),
+        };
+        assert!(at_threshold.has_supermajority());
+    }
+
+    #[test]
+    fn verify_merkle_branch_round_trip() {
+        let leaf = KeccakHash(pack_uint64(1));
+        let sibling = KeccakHash(pack_uint64(2));
+
+        // generalized index 2 (binary `10`): leaf is the *left* child at
+        // depth 1, so the root is `hash(leaf || sibling)`
+        let root_leaf_left = KeccakHash(sha256_pair(&leaf.0, &sibling.0));
+        assert!(verify_merkle_branch(
+            &vec![sibling.clone()],
+            &leaf,
+            2,
+            &root_leaf_left
+        ));
+        // the same branch must not verify against the wrong root
+        assert!(!verify_merkle_branch(
+            &vec![sibling.clone()],
+            &leaf,
+            2,
+            &KeccakHash(pack_uint64(99))
+        ));
+
+        // generalized index 3 (binary `11`): leaf is the *right* child,
+        // so the root is `hash(sibling || leaf)` - a real proof's sibling
+        // can land on either side, and a verifier that always hashes in
+        // the same order (ignoring the index) would wrongly accept this
+        // branch against `root_leaf_left` above, or reject it here
+        let root_leaf_right = KeccakHash(sha256_pair(&sibling.0, &leaf.0));
+        assert!(verify_merkle_branch(
+            &vec![sibling.clone()],
+            &leaf,
+            3,
+            &root_leaf_right
+        ));
+        assert!(!verify_merkle_branch(
+            &vec![sibling],
+            &leaf,
+            3,
+            &root_leaf_left
+        ));
+    }
+
+    #[test]
+    fn beacon_block_header_root_matches_manual_merkleization() {
+        let header = test_header(42);
+        let leaves = [
+            pack_uint64(42),
+            pack_uint64(0),
+            header.parent_root.0,
+            header.state_root.0,
+            header.body_root.0,
+        ];
+        // 5 leaves pad to 8: three levels of pairwise SHA256, with the
+        // last three leaves filled in with the zero hash
+        let zero = [0u8; 32];
+        let level1 = [
+            sha256_pair(&leaves[0], &leaves[1]),
+            sha256_pair(&leaves[2], &leaves[3]),
+            sha256_pair(&leaves[4], &zero),
+            sha256_pair(&zero, &zero),
+        ];
+        let level2 = [
+            sha256_pair(&level1[0], &level1[1]),
+            sha256_pair(&level1[2], &level1[3]),
+        ];
+        let expected = sha256_pair(&level2[0], &level2[1]);
+
+        assert_eq!(header.root().0, expected);
+    }
+
+    #[test]
+    fn compute_domain_matches_manual_ssz_merkleization() {
+        let fork_version = [1, 2, 3, 4];
+        let genesis_validators_root = keccak_hash("genesis");
+
+        // independently reproduce `compute_fork_data_root`/`compute_domain`
+        // by hand, rather than reusing the functions under test, so this
+        // doesn't just prove `compute_domain` is consistent with itself
+        let mut version_chunk = [0u8; 32];
+        version_chunk[..4].copy_from_slice(&fork_version);
+        let fork_data_root =
+            sha256_pair(&version_chunk, &genesis_validators_root.0);
+        let mut expected_domain = [0u8; 32];
+        expected_domain[..4].copy_from_slice(&DOMAIN_SYNC_COMMITTEE);
+        expected_domain[4..].copy_from_slice(&fork_data_root[..28]);
+
+        assert_eq!(
+            compute_domain(fork_version, genesis_validators_root),
+            expected_domain
+        );
+
+        let header = test_header(100);
+        let expected_signing_root =
+            sha256_pair(&header.root().0, &expected_domain);
+        assert_eq!(
+            compute_signing_root(&header, expected_domain).0,
+            expected_signing_root
+        );
+    }
+
+    #[test]
+    fn verify_sync_aggregate_signature_accepts_valid_and_rejects_forged() {
+        let fork_version = [1, 2, 3, 4];
+        let genesis_validators_root = keccak_hash("genesis");
+        let header = test_header(100);
+
+        let (secret_key, pubkey) = keypair(7);
+        let committee = SyncCommittee {
+            pubkeys: vec![pubkey],
+            aggregate_pubkey: pubkey,
+        };
+        let valid_signature =
+            sign(&secret_key, &header, fork_version, genesis_validators_root);
+        let sync_aggregate = SyncAggregate {
+            sync_committee_bits: vec![true],
+            sync_committee_signature: valid_signature,
+        };
+
+        assert!(verify_sync_aggregate_signature(
+            &sync_aggregate,
+            &committee,
+            &header,
+            fork_version,
+            genesis_validators_root,
+        ));
+
+        // a forged/zero signature must be rejected, not waved through
+        let forged = SyncAggregate {
+            sync_committee_bits: vec![true],
+            sync_committee_signature: BlsSignature([0u8; 96]),
+        };
+        assert!(!verify_sync_aggregate_signature(
+            &forged,
+            &committee,
+            &header,
+            fork_version,
+            genesis_validators_root,
+        ));
+
+        // a signature produced over a different header must not verify
+        // against this one
+        let other_header = test_header(101);
+        let wrong_signature = sign(
+            &secret_key,
+            &other_header,
+            fork_version,
+            genesis_validators_root,
+        );
+        let mismatched = SyncAggregate {
+            sync_committee_bits: vec![true],
+            sync_committee_signature: wrong_signature,
+        };
+        assert!(!verify_sync_aggregate_signature(
+            &mismatched,
+            &committee,
+            &header,
+            fork_version,
+            genesis_validators_root,
+        ));
+    }
+}